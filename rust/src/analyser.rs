@@ -2,11 +2,16 @@
 //!
 //! Unified interface for multiple analysis methods:
 //! - Gammatone filterbank (auditory model)
+//! - Multi-resolution FFT (overlapping STFT, better for offline/music use)
 //!
 //! Designed for real-time audio visualization with perceptual accuracy.
 
+use crate::chroma::{ChromaAccumulator, Mode, PitchClass};
 use crate::gammatone::GammatoneFilterbank;
+use crate::multires::MultiResFftFilterbank;
+use crate::pitch::{self, PitchEstimate};
 use crate::scales::{BandInfo, Scale};
+use crate::slm::{SoundLevelMeter, TimeWeighting, Weighting};
 
 //=============================================================================
 // Analysis Mode
@@ -18,9 +23,11 @@ pub enum AnalysisMode {
     /// Gammatone filterbank (auditory model, lowest latency)
     #[default]
     Gammatone,
+    /// Multi-resolution STFT, better low-frequency resolution for
+    /// offline/music use at the cost of latency
+    MultiResFFT,
     // Future modes:
     // BarkCQT,     // Bark-spaced constant-Q transform
-    // MultiResFFT, // Multi-resolution STFT
     // Reassigned   // Reassigned spectrogram
 }
 
@@ -38,6 +45,8 @@ pub struct AnalyserBuilder {
     max_hz: f32,
     sample_rate: f32,
     smoothing_ms: f32,
+    weighting: Weighting,
+    time_weighting: TimeWeighting,
 }
 
 impl Default for AnalyserBuilder {
@@ -50,6 +59,8 @@ impl Default for AnalyserBuilder {
             max_hz: 20000.0,
             sample_rate: 48000.0,
             smoothing_ms: 5.0,
+            weighting: Weighting::default(),
+            time_weighting: TimeWeighting::default(),
         }
     }
 }
@@ -97,6 +108,18 @@ impl AnalyserBuilder {
         self
     }
 
+    /// Set the IEC 61672 frequency weighting used for the sound-level-meter mode
+    pub fn weighting(mut self, weighting: Weighting) -> Self {
+        self.weighting = weighting;
+        self
+    }
+
+    /// Set the time weighting (Fast/Slow) used for the sound-level-meter mode
+    pub fn time_weighting(mut self, time_weighting: TimeWeighting) -> Self {
+        self.time_weighting = time_weighting;
+        self
+    }
+
     /// Build the analyser
     #[must_use]
     pub fn build(self) -> Analyser {
@@ -108,12 +131,19 @@ impl AnalyserBuilder {
             .smoothing(self.smoothing_ms)
             .build();
 
+        let slm = SoundLevelMeter::new(gammatone.bands(), self.weighting, self.time_weighting);
+        let multires =
+            MultiResFftFilterbank::new(gammatone.bands().to_vec(), self.sample_rate, self.smoothing_ms);
+
         Analyser {
             mode: self.mode,
             num_bands: self.num_bands,
             sample_rate: self.sample_rate,
             gammatone,
-            mono_buffer: Vec::new(),
+            multires,
+            slm,
+            chroma: ChromaAccumulator::new(),
+            last_block: Vec::new(),
         }
     }
 }
@@ -145,7 +175,10 @@ pub struct Analyser {
     num_bands: usize,
     sample_rate: f32,
     gammatone: GammatoneFilterbank,
-    mono_buffer: Vec<f32>,
+    multires: MultiResFftFilterbank,
+    slm: SoundLevelMeter,
+    chroma: ChromaAccumulator,
+    last_block: Vec<f32>,
 }
 
 impl Default for Analyser {
@@ -168,6 +201,9 @@ impl Analyser {
     /// Reset analyser state
     pub fn reset(&mut self) {
         self.gammatone.reset();
+        self.multires.reset();
+        self.slm.reset();
+        self.chroma.reset();
     }
 
     /// Process a block of samples and return the envelope
@@ -175,27 +211,62 @@ impl Analyser {
     /// The returned slice contains the smoothed magnitude for each frequency band.
     #[must_use]
     pub fn process(&mut self, input: &[f32]) -> &[f32] {
-        match self.mode {
+        self.last_block.clear();
+        self.last_block.extend_from_slice(input);
+
+        let envelope = match self.mode {
             AnalysisMode::Gammatone => {
-                self.gammatone.process(input);
+                self.gammatone.process_block(input);
                 self.gammatone.envelope()
             }
-        }
+            AnalysisMode::MultiResFFT => {
+                self.multires.process_block(input);
+                self.multires.envelope()
+            }
+        };
+
+        let dt = input.len() as f32 / self.sample_rate;
+        self.slm.update(envelope, dt);
+        self.chroma.update(self.gammatone.bands(), envelope);
+
+        envelope
     }
 
     /// Process a stereo block (averages L+R) and return the envelope
+    ///
+    /// In [`AnalysisMode::Gammatone`], feeds each mixed L/R pair straight into
+    /// the filters, recording it in `last_block` (for [`Analyser::pitch`])
+    /// alongside rather than filtering from that buffer. [`AnalysisMode::MultiResFFT`]
+    /// needs the whole block at once for its FFT, so it mixes into
+    /// `last_block` first and processes that.
     #[must_use]
     pub fn process_stereo(&mut self, left: &[f32], right: &[f32]) -> &[f32] {
         let num_samples = left.len().min(right.len());
 
-        self.mono_buffer.resize(num_samples, 0.0);
-        for i in 0..num_samples {
-            self.mono_buffer[i] = (left[i] + right[i]) * 0.5;
-        }
+        self.last_block.clear();
+        let envelope = match self.mode {
+            AnalysisMode::Gammatone => {
+                for i in 0..num_samples {
+                    let mono_sample = (left[i] + right[i]) * 0.5;
+                    self.last_block.push(mono_sample);
+                    self.gammatone.process(mono_sample);
+                }
+                self.gammatone.envelope()
+            }
+            AnalysisMode::MultiResFFT => {
+                for i in 0..num_samples {
+                    self.last_block.push((left[i] + right[i]) * 0.5);
+                }
+                self.multires.process_block(&self.last_block);
+                self.multires.envelope()
+            }
+        };
+
+        let dt = num_samples as f32 / self.sample_rate;
+        self.slm.update(envelope, dt);
+        self.chroma.update(self.gammatone.bands(), envelope);
 
-        // Need to clone to avoid borrow issues
-        let mono = self.mono_buffer.clone();
-        self.process(&mono)
+        envelope
     }
 
     /// Get the current envelope (smoothed magnitudes)
@@ -203,13 +274,19 @@ impl Analyser {
     /// Returns the same data as the last `process()` call.
     #[must_use]
     pub fn envelope(&self) -> &[f32] {
-        self.gammatone.envelope()
+        match self.mode {
+            AnalysisMode::Gammatone => self.gammatone.envelope(),
+            AnalysisMode::MultiResFFT => self.multires.envelope(),
+        }
     }
 
     /// Get the envelope in decibels
     #[must_use]
     pub fn envelope_db(&self) -> Vec<f32> {
-        self.gammatone.envelope_db(-100.0)
+        match self.mode {
+            AnalysisMode::Gammatone => self.gammatone.envelope_db(-100.0),
+            AnalysisMode::MultiResFFT => self.multires.envelope_db(-100.0),
+        }
     }
 
     /// Get the number of frequency bands
@@ -235,11 +312,52 @@ impl Analyser {
     pub fn bands(&self) -> &[BandInfo] {
         self.gammatone.bands()
     }
+
+    /// Get the current time-weighted sound level in dB (IEC 61672 weighting)
+    ///
+    /// Reflects the last block passed to [`Analyser::process`], smoothed by
+    /// the configured [`TimeWeighting`].
+    #[must_use]
+    pub fn spl(&self) -> f32 {
+        self.slm.level_db()
+    }
+
+    /// Get the equivalent-continuous level (Leq) accumulated since the last
+    /// [`Analyser::reset`], in dB
+    #[must_use]
+    pub fn leq(&self) -> f32 {
+        self.slm.leq_db()
+    }
+
+    /// Get the normalized 12-bin chroma (pitch-class) vector for the last
+    /// block passed to [`Analyser::process`]
+    #[must_use]
+    pub fn chroma(&self) -> [f32; 12] {
+        self.chroma.chroma()
+    }
+
+    /// Estimate the musical key (tonic and major/minor mode) from the chroma
+    /// accumulated across every call to [`Analyser::process`] since the last
+    /// [`Analyser::reset`]
+    #[must_use]
+    pub fn estimate_key(&self) -> (PitchClass, Mode) {
+        self.chroma.estimate_key()
+    }
+
+    /// Detect the fundamental frequency of the last block passed to
+    /// [`Analyser::process`] via the McLeod Pitch Method
+    ///
+    /// Returns `None` for silence, noise, or blocks too short to analyze.
+    #[must_use]
+    pub fn pitch(&self) -> Option<PitchEstimate> {
+        pitch::mcleod_pitch(&self.last_block, self.sample_rate)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::sine;
     use std::f32::consts::PI;
 
     #[test]
@@ -306,6 +424,73 @@ mod tests {
         assert_eq!(envelope.len(), 40);
     }
 
+    #[test]
+    fn test_spl_and_leq_respond_to_signal() {
+        let mut analyser = Analyser::builder()
+            .weighting(Weighting::Z)
+            .time_weighting(TimeWeighting::Fast)
+            .build();
+
+        let signal = sine(1000.0, 48000.0, 4800);
+
+        let _ = analyser.process(&signal);
+
+        assert!(analyser.spl().is_finite());
+        assert!(analyser.leq().is_finite());
+    }
+
+    #[test]
+    fn test_chroma_and_key_from_signal() {
+        let mut analyser = Analyser::new();
+
+        let signal = sine(440.0, 48000.0, 4800);
+
+        let _ = analyser.process(&signal);
+
+        let chroma = analyser.chroma();
+        let sum: f32 = chroma.iter().sum();
+        assert!((sum - 1.0).abs() < 1e-3 || sum == 0.0);
+
+        let (_tonic, _mode) = analyser.estimate_key();
+    }
+
+    #[test]
+    fn test_pitch_tracks_sine() {
+        let mut analyser = Analyser::new();
+
+        let signal = sine(220.0, 48000.0, 2048);
+
+        let _ = analyser.process(&signal);
+
+        let estimate = analyser.pitch().expect("should detect a pitch");
+        assert!((estimate.frequency_hz - 220.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_multires_fft_mode_returns_envelope() {
+        let mut analyser = Analyser::builder().mode(AnalysisMode::MultiResFFT).build();
+
+        let signal: Vec<f32> = (0..8192)
+            .map(|i| (2.0 * PI * 1000.0 * i as f32 / 48000.0).sin())
+            .collect();
+
+        let envelope = analyser.process(&signal);
+        assert_eq!(envelope.len(), 40);
+
+        let (peak_band, _) = envelope
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .unwrap();
+
+        let peak_freq = analyser.center_hz(peak_band);
+        assert!(
+            peak_freq > 700.0 && peak_freq < 1400.0,
+            "Peak at {} Hz",
+            peak_freq
+        );
+    }
+
     #[test]
     fn test_envelope_db() {
         let mut analyser = Analyser::new();
@@ -0,0 +1,246 @@
+//! Chromagram and Key/Mode Estimation
+//!
+//! Folds the per-band envelope into a 12-element pitch-class vector
+//! (chroma) and estimates the musical key and major/minor mode via the
+//! Krumhansl-Schmuckler key-finding algorithm.
+
+use crate::mathcompat::{log2f, roundf, sqrtf};
+use crate::scales::BandInfo;
+
+//=============================================================================
+// Pitch Class / Mode
+//=============================================================================
+
+/// One of the 12 pitch classes of equal temperament
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PitchClass {
+    C,
+    CSharp,
+    D,
+    DSharp,
+    E,
+    F,
+    FSharp,
+    G,
+    GSharp,
+    A,
+    ASharp,
+    B,
+}
+
+impl PitchClass {
+    const ALL: [PitchClass; 12] = [
+        PitchClass::C,
+        PitchClass::CSharp,
+        PitchClass::D,
+        PitchClass::DSharp,
+        PitchClass::E,
+        PitchClass::F,
+        PitchClass::FSharp,
+        PitchClass::G,
+        PitchClass::GSharp,
+        PitchClass::A,
+        PitchClass::ASharp,
+        PitchClass::B,
+    ];
+
+    /// Pitch class from a chroma bin index (0 = C, 1 = C#, ...)
+    pub fn from_index(index: usize) -> PitchClass {
+        Self::ALL[index % 12]
+    }
+}
+
+/// Major or minor mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Major,
+    Minor,
+}
+
+//=============================================================================
+// Key Profiles (Krumhansl-Schmuckler)
+//=============================================================================
+
+const MAJOR_PROFILE: [f32; 12] = [
+    6.35, 2.23, 3.48, 2.33, 4.38, 4.09, 2.52, 5.19, 2.39, 3.66, 2.29, 2.88,
+];
+
+const MINOR_PROFILE: [f32; 12] = [
+    6.33, 2.68, 3.52, 5.38, 2.60, 3.53, 2.54, 4.75, 3.98, 2.69, 3.34, 3.17,
+];
+
+//=============================================================================
+// Chroma Accumulator
+//=============================================================================
+
+/// Accumulates a 12-bin pitch-class (chroma) vector from the per-band
+/// envelope, frame by frame, for whole-signal key estimation
+#[derive(Debug, Clone)]
+pub struct ChromaAccumulator {
+    frame: [f32; 12],
+    accumulated: [f32; 12],
+}
+
+impl Default for ChromaAccumulator {
+    fn default() -> Self {
+        Self {
+            frame: [0.0; 12],
+            accumulated: [0.0; 12],
+        }
+    }
+}
+
+impl ChromaAccumulator {
+    /// Create a new, empty accumulator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold a block's per-band magnitudes into the chroma vector
+    pub fn update(&mut self, bands: &[BandInfo], magnitudes: &[f32]) {
+        self.frame = [0.0; 12];
+
+        for (band, &mag) in bands.iter().zip(magnitudes.iter()) {
+            if band.center_hz <= 0.0 {
+                continue;
+            }
+            let pitch_class = midi_pitch_class(band.center_hz);
+            self.frame[pitch_class] += mag;
+        }
+
+        normalize(&mut self.frame);
+        for i in 0..12 {
+            self.accumulated[i] += self.frame[i];
+        }
+    }
+
+    /// Normalized chroma vector for the most recent block
+    pub fn chroma(&self) -> [f32; 12] {
+        self.frame
+    }
+
+    /// Normalized chroma vector averaged over every block seen so far
+    pub fn accumulated_chroma(&self) -> [f32; 12] {
+        let mut chroma = self.accumulated;
+        normalize(&mut chroma);
+        chroma
+    }
+
+    /// Reset the running accumulation, keeping the last per-block frame
+    pub fn reset(&mut self) {
+        self.accumulated = [0.0; 12];
+    }
+
+    /// Estimate the key (tonic pitch class and major/minor mode) from the
+    /// chroma accumulated so far, via Krumhansl-Schmuckler correlation
+    pub fn estimate_key(&self) -> (PitchClass, Mode) {
+        estimate_key(&self.accumulated_chroma())
+    }
+}
+
+fn midi_pitch_class(hz: f32) -> usize {
+    let midi = roundf(12.0 * log2f(hz / 440.0) + 69.0);
+    (midi as i32).rem_euclid(12) as usize
+}
+
+fn normalize(chroma: &mut [f32; 12]) {
+    let sum: f32 = chroma.iter().sum();
+    if sum > 0.0 {
+        for bin in chroma.iter_mut() {
+            *bin /= sum;
+        }
+    }
+}
+
+/// Estimate the key of a chroma vector by correlating it against all 12
+/// rotations of the major and minor Krumhansl-Schmuckler profiles
+pub fn estimate_key(chroma: &[f32; 12]) -> (PitchClass, Mode) {
+    let mut best = (0usize, Mode::Major, f32::NEG_INFINITY);
+
+    for tonic in 0..12 {
+        let major_corr = pearson_correlation(chroma, &rotate(&MAJOR_PROFILE, tonic));
+        let minor_corr = pearson_correlation(chroma, &rotate(&MINOR_PROFILE, tonic));
+
+        if major_corr > best.2 {
+            best = (tonic, Mode::Major, major_corr);
+        }
+        if minor_corr > best.2 {
+            best = (tonic, Mode::Minor, minor_corr);
+        }
+    }
+
+    (PitchClass::from_index(best.0), best.1)
+}
+
+fn rotate(profile: &[f32; 12], tonic: usize) -> [f32; 12] {
+    let mut rotated = [0.0; 12];
+    for i in 0..12 {
+        rotated[(i + tonic) % 12] = profile[i];
+    }
+    rotated
+}
+
+fn pearson_correlation(a: &[f32; 12], b: &[f32; 12]) -> f32 {
+    let mean_a = a.iter().sum::<f32>() / 12.0;
+    let mean_b = b.iter().sum::<f32>() / 12.0;
+
+    let mut cov = 0.0;
+    let mut var_a = 0.0;
+    let mut var_b = 0.0;
+    for i in 0..12 {
+        let da = a[i] - mean_a;
+        let db = b[i] - mean_b;
+        cov += da * db;
+        var_a += da * da;
+        var_b += db * db;
+    }
+
+    if var_a <= 0.0 || var_b <= 0.0 {
+        0.0
+    } else {
+        cov / (sqrtf(var_a) * sqrtf(var_b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn band(center_hz: f32) -> BandInfo {
+        BandInfo {
+            center_hz,
+            bandwidth_hz: 1.0,
+            low_hz: center_hz - 0.5,
+            high_hz: center_hz + 0.5,
+        }
+    }
+
+    #[test]
+    fn test_midi_pitch_class_a440_is_a() {
+        assert_eq!(midi_pitch_class(440.0), 9); // A = index 9
+    }
+
+    #[test]
+    fn test_chroma_normalizes_to_one() {
+        let mut acc = ChromaAccumulator::new();
+        let bands = [band(440.0), band(880.0)];
+        acc.update(&bands, &[1.0, 1.0]);
+
+        let sum: f32 = acc.chroma().iter().sum();
+        assert!((sum - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_estimate_key_c_major_chord() {
+        // C, E, G reinforced repeatedly -> C major should win
+        let mut acc = ChromaAccumulator::new();
+        let bands = [band(261.63), band(329.63), band(392.0)]; // C4, E4, G4
+        for _ in 0..8 {
+            acc.update(&bands, &[1.0, 1.0, 1.0]);
+        }
+
+        let (tonic, mode) = acc.estimate_key();
+        assert_eq!(tonic, PitchClass::C);
+        assert_eq!(mode, Mode::Major);
+    }
+}
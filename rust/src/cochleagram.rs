@@ -0,0 +1,128 @@
+//! Cochlear Filterbank (raw-waveform gammatone channels)
+//!
+//! Unlike [`crate::gammatone::GammatoneFilterbank`], which only exposes
+//! magnitude envelopes, [`Cochleagram`] returns the filtered *time-domain*
+//! signal for every ERB-spaced channel — the representation correlograms
+//! and other cochlear-model front-ends are built on. Each channel reuses
+//! [`GammatoneFilter`], the same complex-resonator cascade and gain
+//! normalization already validated by [`crate::gammatone::GammatoneFilterbank`],
+//! and takes the real part of its output as the raw waveform.
+//!
+//! Named `Cochleagram` rather than `GammatoneFilterbank` (as originally
+//! requested) to avoid colliding with the existing envelope-only
+//! [`crate::gammatone::GammatoneFilterbank`] — same request, different name.
+
+use crate::gammatone::GammatoneFilter;
+use crate::scales::{erb_bandwidth, generate_bands, Scale};
+
+/// A bank of ERB-spaced gammatone channels returning raw filtered
+/// waveforms, for correlograms and other cochlear-model front-ends
+#[derive(Debug, Clone)]
+pub struct Cochleagram {
+    channels: Vec<GammatoneFilter>,
+}
+
+impl Cochleagram {
+    /// Build `num_channels` ERB-spaced gammatone channels spanning
+    /// `[min_hz, max_hz]`
+    #[must_use]
+    pub fn new(num_channels: usize, min_hz: f32, max_hz: f32, sample_rate: f32) -> Self {
+        let bands = generate_bands(Scale::ERB, num_channels, min_hz, max_hz);
+        let channels = bands
+            .iter()
+            .map(|band| GammatoneFilter::new(band.center_hz, erb_bandwidth(band.center_hz), sample_rate))
+            .collect();
+        Self { channels }
+    }
+
+    /// Reset all channel filter states
+    pub fn reset(&mut self) {
+        for channel in &mut self.channels {
+            channel.reset();
+        }
+    }
+
+    /// Filter `input` through every channel, returning one filtered
+    /// waveform per channel (each the same length as `input`)
+    #[must_use]
+    pub fn process(&mut self, input: &[f32]) -> Vec<Vec<f32>> {
+        self.channels
+            .iter_mut()
+            .map(|channel| {
+                input
+                    .iter()
+                    .map(|&x| {
+                        channel.process(x);
+                        channel.last_real()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Per-channel RMS energy of `input` after filtering — a cheap
+    /// envelope/energy summary for callers that don't need the raw streams
+    #[must_use]
+    pub fn channel_energies(&mut self, input: &[f32]) -> Vec<f32> {
+        self.process(input)
+            .into_iter()
+            .map(|stream| {
+                let sum_sq: f32 = stream.iter().map(|&x| x * x).sum();
+                (sum_sq / stream.len().max(1) as f32).sqrt()
+            })
+            .collect()
+    }
+
+    /// Get the number of channels
+    #[must_use]
+    pub fn num_channels(&self) -> usize {
+        self.channels.len()
+    }
+
+    /// Get the center frequency of a channel in Hz
+    #[must_use]
+    pub fn center_hz(&self, channel: usize) -> f32 {
+        self.channels[channel].center_hz()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::sine;
+
+    #[test]
+    fn test_cochleagram_creation() {
+        let cochleagram = Cochleagram::new(32, 20.0, 20000.0, 48000.0);
+        assert_eq!(cochleagram.num_channels(), 32);
+    }
+
+    #[test]
+    fn test_cochleagram_returns_one_waveform_per_channel() {
+        let mut cochleagram = Cochleagram::new(16, 20.0, 20000.0, 48000.0);
+        let signal = vec![0.1f32; 256];
+
+        let streams = cochleagram.process(&signal);
+        assert_eq!(streams.len(), 16);
+        for stream in &streams {
+            assert_eq!(stream.len(), 256);
+        }
+    }
+
+    #[test]
+    fn test_cochleagram_1khz_sine_peaks_near_1khz_channel() {
+        let mut cochleagram = Cochleagram::new(40, 20.0, 20000.0, 48000.0);
+
+        let signal = sine(1000.0, 48000.0, 4800);
+
+        let energies = cochleagram.channel_energies(&signal);
+        let (peak_channel, _) = energies
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .unwrap();
+
+        let peak_freq = cochleagram.center_hz(peak_channel);
+        assert!(peak_freq > 700.0 && peak_freq < 1400.0, "peak at {peak_freq} Hz");
+    }
+}
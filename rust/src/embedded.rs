@@ -0,0 +1,167 @@
+//! `no_std` / Embedded Real-Time Support
+//!
+//! A fixed-capacity, allocation-free gammatone filterbank for targets
+//! without a heap — microcontrollers, or real-time audio callbacks where
+//! allocation is disallowed. Same per-band envelope as
+//! [`crate::gammatone::GammatoneFilterbank`], but every buffer is a
+//! `[T; N]` living inline in `Self` instead of a `Vec`.
+
+use crate::gammatone::GammatoneFilter;
+use crate::mathcompat::{expf, log10f};
+use crate::scales::{erb_bandwidth, generate_bands_into, BandInfo, Scale};
+
+const EMPTY_BAND: BandInfo = BandInfo {
+    center_hz: 0.0,
+    bandwidth_hz: 0.0,
+    low_hz: 0.0,
+    high_hz: 0.0,
+};
+
+/// A fixed-capacity, allocation-free gammatone filterbank with `N` bands
+#[derive(Debug, Clone)]
+pub struct EmbeddedFilterbank<const N: usize> {
+    bands: [BandInfo; N],
+    filters: [GammatoneFilter; N],
+    magnitudes: [f32; N],
+    smoothed_magnitudes: [f32; N],
+    smooth_coeff: f32,
+}
+
+impl<const N: usize> EmbeddedFilterbank<N> {
+    /// Build a filterbank with `N` bands spanning `[min_hz, max_hz]`
+    ///
+    /// For every scale but [`Scale::Octave`], `N` bands are generated to
+    /// exactly fill the array. `Octave`'s band count is fixed by the
+    /// standard, so choose `N` to match the range you pass in (e.g. via
+    /// `generate_bands` on the host side first) or expect unused trailing
+    /// bands at `center_hz == 0.0`.
+    pub fn new(scale: Scale, min_hz: f32, max_hz: f32, sample_rate: f32, smoothing_ms: f32) -> Self {
+        let mut bands = [EMPTY_BAND; N];
+        generate_bands_into(&mut bands, scale, min_hz, max_hz);
+
+        let mut filters: [GammatoneFilter; N] = core::array::from_fn(|_| GammatoneFilter::default());
+        for (filter, band) in filters.iter_mut().zip(bands.iter()) {
+            let bandwidth_hz = match scale {
+                Scale::Octave { .. } => band.bandwidth_hz,
+                _ => erb_bandwidth(band.center_hz),
+            };
+            filter.configure(band.center_hz, bandwidth_hz, sample_rate);
+        }
+
+        let smooth_coeff = if smoothing_ms > 0.0 {
+            let tau = smoothing_ms / 1000.0;
+            expf(-1.0 / (tau * sample_rate))
+        } else {
+            0.0
+        };
+
+        Self {
+            bands,
+            filters,
+            magnitudes: [0.0; N],
+            smoothed_magnitudes: [0.0; N],
+            smooth_coeff,
+        }
+    }
+
+    /// Reset all filter states
+    pub fn reset(&mut self) {
+        for filter in &mut self.filters {
+            filter.reset();
+        }
+        self.magnitudes = [0.0; N];
+        self.smoothed_magnitudes = [0.0; N];
+    }
+
+    /// Process a single sample through all filters; allocation-free
+    #[inline]
+    pub fn process(&mut self, input: f32) {
+        for (i, filter) in self.filters.iter_mut().enumerate() {
+            let mag = filter.process(input);
+            self.magnitudes[i] = mag;
+            self.smoothed_magnitudes[i] = if self.smooth_coeff > 0.0 {
+                self.smooth_coeff * self.smoothed_magnitudes[i] + (1.0 - self.smooth_coeff) * mag
+            } else {
+                mag
+            };
+        }
+    }
+
+    /// Process a block of samples; allocation-free
+    pub fn process_block(&mut self, input: &[f32]) {
+        for &sample in input {
+            self.process(sample);
+        }
+    }
+
+    /// Process an interleaved-free stereo block, mixing L+R per sample
+    /// directly into the filters with no intermediate mono buffer
+    pub fn process_stereo(&mut self, left: &[f32], right: &[f32]) {
+        let n = left.len().min(right.len());
+        for i in 0..n {
+            self.process((left[i] + right[i]) * 0.5);
+        }
+    }
+
+    /// Get the number of bands
+    pub fn num_bands(&self) -> usize {
+        N
+    }
+
+    /// Get band information
+    pub fn bands(&self) -> &[BandInfo] {
+        &self.bands
+    }
+
+    /// Get the smoothed envelope
+    pub fn envelope(&self) -> &[f32] {
+        &self.smoothed_magnitudes
+    }
+
+    /// Write the envelope in decibels into a caller-supplied buffer;
+    /// allocation-free, available without `std`
+    pub fn envelope_db_into(&self, output: &mut [f32], min_db: f32) {
+        for (o, &mag) in output.iter_mut().zip(self.smoothed_magnitudes.iter()) {
+            *o = if mag > 0.0 { 20.0 * log10f(mag) } else { min_db };
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::test_util::sine;
+
+    #[test]
+    fn test_embedded_filterbank_creation() {
+        let fb: EmbeddedFilterbank<16> = EmbeddedFilterbank::new(Scale::ERB, 20.0, 20000.0, 48000.0, 5.0);
+        assert_eq!(fb.num_bands(), 16);
+    }
+
+    #[test]
+    fn test_embedded_filterbank_1khz_sine() {
+        let mut fb: EmbeddedFilterbank<40> =
+            EmbeddedFilterbank::new(Scale::ERB, 20.0, 20000.0, 48000.0, 5.0);
+
+        let signal = sine(1000.0, 48000.0, 4800);
+        fb.process_block(&signal);
+
+        let (peak_band, _) = fb
+            .envelope()
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .unwrap();
+
+        let peak_freq = fb.bands()[peak_band].center_hz;
+        assert!(peak_freq > 800.0 && peak_freq < 1200.0, "peak at {peak_freq} Hz");
+    }
+
+    #[test]
+    fn test_embedded_filterbank_stereo_no_alloc_path() {
+        let mut fb: EmbeddedFilterbank<8> = EmbeddedFilterbank::new(Scale::ERB, 20.0, 20000.0, 48000.0, 5.0);
+        let signal = vec![0.1f32; 128];
+        fb.process_stereo(&signal, &signal);
+        assert_eq!(fb.envelope().len(), 8);
+    }
+}
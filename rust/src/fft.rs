@@ -0,0 +1,135 @@
+//! Minimal FFT
+//!
+//! No external FFT crate is available, so the multi-resolution STFT
+//! analysis mode ([`crate::analyser::AnalysisMode::MultiResFFT`]) gets a
+//! small self-contained one: an in-place radix-2 Cooley-Tukey FFT plus a
+//! Hann window. Not a general-purpose FFT (sizes must be powers of two),
+//! just enough for that mode's band-energy estimate.
+
+use std::f32::consts::PI;
+
+/// A single complex sample, the FFT's working-buffer element
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Complex32 {
+    pub re: f32,
+    pub im: f32,
+}
+
+impl Complex32 {
+    const fn new(re: f32, im: f32) -> Self {
+        Self { re, im }
+    }
+
+    pub fn magnitude(self) -> f32 {
+        (self.re * self.re + self.im * self.im).sqrt()
+    }
+}
+
+impl std::ops::Add for Complex32 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl std::ops::Sub for Complex32 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl std::ops::Mul for Complex32 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+/// In-place iterative radix-2 Cooley-Tukey FFT
+///
+/// `buffer.len()` must be a power of two.
+pub(crate) fn fft_radix2(buffer: &mut [Complex32]) {
+    let n = buffer.len();
+    debug_assert!(n.is_power_of_two(), "FFT size must be a power of two");
+
+    // Bit-reversal permutation
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if j > i {
+            buffer.swap(i, j);
+        }
+    }
+
+    // Iterative butterfly passes, doubling the sub-transform size each round
+    let mut size = 2;
+    while size <= n {
+        let half = size / 2;
+        let angle_step = -2.0 * PI / size as f32;
+        for start in (0..n).step_by(size) {
+            for k in 0..half {
+                let angle = angle_step * k as f32;
+                let twiddle = Complex32::new(angle.cos(), angle.sin());
+                let even = buffer[start + k];
+                let odd = buffer[start + k + half] * twiddle;
+                buffer[start + k] = even + odd;
+                buffer[start + k + half] = even - odd;
+            }
+        }
+        size *= 2;
+    }
+}
+
+/// Generate a periodic Hann window of length `n`
+pub(crate) fn hann_window(n: usize) -> Vec<f32> {
+    if n <= 1 {
+        return vec![1.0; n];
+    }
+    (0..n)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / n as f32).cos())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fft_of_dc_signal_is_all_energy_in_bin_zero() {
+        let mut buffer = vec![Complex32::new(1.0, 0.0); 16];
+        fft_radix2(&mut buffer);
+
+        assert!((buffer[0].magnitude() - 16.0).abs() < 1e-3);
+        for bin in &buffer[1..] {
+            assert!(bin.magnitude() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_fft_of_sine_peaks_at_matching_bin() {
+        let n = 64;
+        let bin = 4;
+        let mut buffer: Vec<Complex32> = (0..n)
+            .map(|i| Complex32::new((2.0 * PI * bin as f32 * i as f32 / n as f32).sin(), 0.0))
+            .collect();
+        fft_radix2(&mut buffer);
+
+        let (peak_bin, _) = buffer[..n / 2]
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.magnitude().partial_cmp(&b.magnitude()).unwrap())
+            .unwrap();
+        assert_eq!(peak_bin, bin);
+    }
+
+    #[test]
+    fn test_hann_window_starts_at_zero() {
+        let window = hann_window(8);
+        assert!((window[0]).abs() < 1e-6);
+        assert_eq!(window.len(), 8);
+    }
+}
@@ -9,9 +9,12 @@
 //!
 //! Where n=4 (filter order), b=bandwidth, f=center frequency.
 
-use std::f32::consts::PI;
+use core::f32::consts::PI;
 
-use crate::scales::{erb_bandwidth, generate_bands, BandInfo, Scale};
+use crate::mathcompat::{cosf, expf, powi, sinf, sqrtf};
+#[cfg(feature = "std")]
+use crate::scales::generate_bands;
+use crate::scales::{erb_bandwidth, BandInfo, Scale};
 
 //=============================================================================
 // Gammatone Filter (Single Band)
@@ -70,12 +73,12 @@ impl GammatoneFilter {
 
         // Pole radius and angle for complex resonator
         // For 4th order gammatone, we cascade 4 identical 1st-order sections
-        self.r = (-bw).exp();
-        self.cos_omega = omega.cos();
-        self.sin_omega = omega.sin();
+        self.r = expf(-bw);
+        self.cos_omega = cosf(omega);
+        self.sin_omega = sinf(omega);
 
         // Input gain normalization (approximate)
-        self.gain = (1.0 - self.r).powi(4) * 2.0;
+        self.gain = powi(1.0 - self.r, 4) * 2.0;
 
         self.reset();
     }
@@ -110,7 +113,7 @@ impl GammatoneFilter {
         }
 
         // Envelope = magnitude of complex output
-        (real * real + imag * imag).sqrt()
+        sqrtf(real * real + imag * imag)
     }
 
     /// Process a block of samples
@@ -124,6 +127,13 @@ impl GammatoneFilter {
     pub fn center_hz(&self) -> f32 {
         self.center_hz
     }
+
+    /// Real part of the last complex resonator output — a real-valued,
+    /// band-limited time-domain signal (the filter's "in-phase" channel)
+    /// for callers that want a raw waveform rather than just the envelope
+    pub fn last_real(&self) -> f32 {
+        self.state_real[3]
+    }
 }
 
 //=============================================================================
@@ -132,6 +142,7 @@ impl GammatoneFilter {
 //=============================================================================
 
 /// Configuration for the gammatone filterbank
+#[cfg(feature = "std")]
 #[derive(Debug, Clone)]
 pub struct FilterbankConfig {
     /// Number of frequency bands
@@ -148,6 +159,7 @@ pub struct FilterbankConfig {
     pub smoothing_ms: f32,
 }
 
+#[cfg(feature = "std")]
 impl Default for FilterbankConfig {
     fn default() -> Self {
         Self {
@@ -161,7 +173,64 @@ impl Default for FilterbankConfig {
     }
 }
 
+//=============================================================================
+// Filterbank Builder
+//=============================================================================
+
+/// Builder for creating a [`GammatoneFilterbank`] with custom configuration
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct GammatoneFilterbankBuilder {
+    config: FilterbankConfig,
+}
+
+#[cfg(feature = "std")]
+impl GammatoneFilterbankBuilder {
+    /// Create a new builder with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of frequency bands
+    pub fn bands(mut self, num_bands: usize) -> Self {
+        self.config.num_bands = num_bands;
+        self
+    }
+
+    /// Set the frequency range in Hz
+    pub fn range(mut self, min_hz: f32, max_hz: f32) -> Self {
+        self.config.min_hz = min_hz;
+        self.config.max_hz = max_hz;
+        self
+    }
+
+    /// Set the sample rate in Hz
+    pub fn sample_rate(mut self, sample_rate: f32) -> Self {
+        self.config.sample_rate = sample_rate;
+        self
+    }
+
+    /// Set the frequency scale used for band spacing
+    pub fn scale(mut self, spacing: Scale) -> Self {
+        self.config.spacing = spacing;
+        self
+    }
+
+    /// Set the envelope smoothing time in milliseconds
+    pub fn smoothing(mut self, smoothing_ms: f32) -> Self {
+        self.config.smoothing_ms = smoothing_ms;
+        self
+    }
+
+    /// Build the filterbank
+    #[must_use]
+    pub fn build(self) -> GammatoneFilterbank {
+        GammatoneFilterbank::with_config(self.config)
+    }
+}
+
 /// A bank of gammatone filters for spectrum analysis
+#[cfg(feature = "std")]
 #[derive(Debug, Clone)]
 pub struct GammatoneFilterbank {
     config: FilterbankConfig,
@@ -172,6 +241,7 @@ pub struct GammatoneFilterbank {
     smooth_coeff: f32,
 }
 
+#[cfg(feature = "std")]
 impl Default for GammatoneFilterbank {
     fn default() -> Self {
         let mut fb = Self {
@@ -187,12 +257,18 @@ impl Default for GammatoneFilterbank {
     }
 }
 
+#[cfg(feature = "std")]
 impl GammatoneFilterbank {
     /// Create a new filterbank with default configuration
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Create a builder for custom configuration
+    pub fn builder() -> GammatoneFilterbankBuilder {
+        GammatoneFilterbankBuilder::new()
+    }
+
     /// Create a filterbank with the given configuration
     pub fn with_config(config: FilterbankConfig) -> Self {
         let mut fb = Self {
@@ -215,10 +291,16 @@ impl GammatoneFilterbank {
         self.bands = generate_bands(config.spacing, config.num_bands, config.min_hz, config.max_hz);
 
         // Create filters
-        self.filters = Vec::with_capacity(config.num_bands);
+        self.filters = Vec::with_capacity(self.bands.len());
         for band in &self.bands {
-            // Use ERB bandwidth for each filter (standard for gammatone)
-            let bw = erb_bandwidth(band.center_hz);
+            // Octave bands have standardized edges; use those directly rather
+            // than the ERB approximation so the filter matches the band it's
+            // labeled as. Every other scale keeps the ERB bandwidth, which is
+            // the standard choice for gammatone filters.
+            let bw = match config.spacing {
+                Scale::Octave { .. } => band.bandwidth_hz,
+                _ => erb_bandwidth(band.center_hz),
+            };
             self.filters
                 .push(GammatoneFilter::new(band.center_hz, bw, config.sample_rate));
         }
@@ -231,9 +313,11 @@ impl GammatoneFilterbank {
             self.smooth_coeff = 0.0;
         }
 
-        // Allocate output buffers
-        self.magnitudes = vec![0.0; config.num_bands];
-        self.smoothed_magnitudes = vec![0.0; config.num_bands];
+        // Allocate output buffers. Most scales produce exactly `num_bands`
+        // bands, but `Scale::Octave` fixes its own band count from the
+        // requested range, so size off the actual band list.
+        self.magnitudes = vec![0.0; self.bands.len()];
+        self.smoothed_magnitudes = vec![0.0; self.bands.len()];
     }
 
     /// Reset all filter states
@@ -271,7 +355,7 @@ impl GammatoneFilterbank {
 
     /// Get the number of bands
     pub fn num_bands(&self) -> usize {
-        self.config.num_bands
+        self.bands.len()
     }
 
     /// Get raw magnitudes (not smoothed)
@@ -289,6 +373,24 @@ impl GammatoneFilterbank {
         &self.bands
     }
 
+    /// Get band information (alias of [`GammatoneFilterbank::band_info`] for
+    /// callers that think in terms of a generic analysis "band set")
+    pub fn bands(&self) -> &[BandInfo] {
+        &self.bands
+    }
+
+    /// Get the smoothed envelope (alias of [`GammatoneFilterbank::smoothed_magnitudes`])
+    pub fn envelope(&self) -> &[f32] {
+        &self.smoothed_magnitudes
+    }
+
+    /// Get the smoothed envelope in decibels
+    pub fn envelope_db(&self, min_db: f32) -> Vec<f32> {
+        let mut output = vec![0.0; self.smoothed_magnitudes.len()];
+        self.magnitudes_db(&mut output, min_db);
+        output
+    }
+
     /// Get magnitude for a specific band
     pub fn magnitude(&self, band: usize) -> f32 {
         self.magnitudes[band]
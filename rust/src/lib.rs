@@ -10,30 +10,27 @@
 //! ## Quick Start
 //!
 //! ```rust
-//! use cortix::{Analyser, AnalyserConfig, Scale};
+//! use cortix::{Analyser, Scale};
 //!
 //! // Create analyser: 48kHz, 40 ERB-spaced bands
-//! let config = AnalyserConfig {
-//!     sample_rate: 48000.0,
-//!     num_bands: 40,
-//!     scale: Scale::ERB,
-//!     ..Default::default()
-//! };
-//!
-//! let mut analyser = Analyser::with_config(config);
+//! let mut analyser = Analyser::builder()
+//!     .sample_rate(48000.0)
+//!     .bands(40)
+//!     .scale(Scale::ERB)
+//!     .build();
 //!
 //! // Process audio
 //! let audio_buffer: Vec<f32> = vec![0.0; 512]; // Your audio data
-//! analyser.process_block(&audio_buffer);
+//! analyser.process(&audio_buffer);
 //!
 //! // Get results
-//! let mut magnitudes_db = vec![0.0; analyser.num_bands()];
-//! analyser.get_magnitudes_db(&mut magnitudes_db);
+//! let magnitudes_db = analyser.envelope_db();
 //! ```
 //!
 //! ## Features
 //!
 //! - **Gammatone Filterbank** - Auditory model with true frequency resolution
+//! - **Multi-Resolution FFT** - Overlapping STFT mode for offline/music analysis
 //! - **Multiple Scales** - Bark, ERB, Mel, Log, and Linear frequency spacing
 //! - **Real-time Performance** - Sub-millisecond latency, efficient per-sample processing
 //! - **Perceptually Accurate** - Based on auditory neuroscience research
@@ -48,17 +45,55 @@
 //! | ERB | Equivalent rectangular bandwidth | Auditory models |
 //! | Mel | Pitch perception | Speech recognition |
 
+// `std` is the default feature; building without it (e.g. for
+// microcontrollers) drops the heap-based `Analyser`/`GammatoneFilterbank`
+// in favor of `embedded::EmbeddedFilterbank`, which allocates nothing.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
 pub mod analyser;
+pub mod chroma;
+#[cfg(feature = "std")]
+pub mod cochleagram;
+pub mod embedded;
+#[cfg(feature = "std")]
+mod fft;
 pub mod gammatone;
+mod mathcompat;
+#[cfg(feature = "std")]
+pub mod multires;
+pub mod note;
+#[cfg(feature = "std")]
+pub mod pitch;
 pub mod scales;
+#[cfg(feature = "std")]
+pub mod slm;
+#[cfg(all(test, feature = "std"))]
+mod test_util;
 
 // Re-export main types at crate root
-pub use analyser::{AnalysisMode, Analyser, AnalyserConfig};
-pub use gammatone::{FilterbankConfig, GammatoneFilter, GammatoneFilterbank};
+#[cfg(feature = "std")]
+pub use analyser::{AnalysisMode, Analyser, AnalyserBuilder};
+pub use chroma::{ChromaAccumulator, Mode, PitchClass};
+#[cfg(feature = "std")]
+pub use cochleagram::Cochleagram;
+pub use embedded::EmbeddedFilterbank;
+pub use gammatone::GammatoneFilter;
+#[cfg(feature = "std")]
+pub use gammatone::{FilterbankConfig, GammatoneFilterbank, GammatoneFilterbankBuilder};
+#[cfg(feature = "std")]
+pub use multires::MultiResFftFilterbank;
+pub use note::{hz_to_note, note_to_hz, NoteName};
+#[cfg(feature = "std")]
+pub use pitch::PitchEstimate;
 pub use scales::{
-    bark_to_hz, critical_bandwidth, erb_bandwidth, erb_to_hz, generate_bands, hz_to_bark,
-    hz_to_erb, hz_to_mel, mel_to_hz, BandInfo, Scale,
+    bark_to_hz, critical_bandwidth, erb_bandwidth, erb_to_hz, generate_bands_into, hz_to_bark,
+    hz_to_erb, hz_to_mel, mel_to_hz, octave_preferred_label, BandInfo, Scale,
 };
+#[cfg(feature = "std")]
+pub use scales::{band_energies, filterbank, generate_bands, BandAggregation};
+#[cfg(feature = "std")]
+pub use slm::{SoundLevelMeter, TimeWeighting, Weighting};
 
 /// Library version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,129 @@
+//! Math Compatibility Shim
+//!
+//! The transcendental and rounding functions used throughout the crate
+//! (`exp`, `sqrt`, `log2`, `log10`, `powf`, `powi`, `round`, `ceil`,
+//! `floor`, ...) are `std`-only on `f32` — `core` doesn't ship them since
+//! they need libm. This module routes every such call through `libm` when
+//! the `std` feature is off, so the hot paths (gammatone filters, band
+//! spacing) work unchanged on `no_std` targets.
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn expf(x: f32) -> f32 {
+    x.exp()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn expf(x: f32) -> f32 {
+    libm::expf(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn sqrtf(x: f32) -> f32 {
+    x.sqrt()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn sqrtf(x: f32) -> f32 {
+    libm::sqrtf(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn cosf(x: f32) -> f32 {
+    x.cos()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn cosf(x: f32) -> f32 {
+    libm::cosf(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn sinf(x: f32) -> f32 {
+    x.sin()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn sinf(x: f32) -> f32 {
+    libm::sinf(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn roundf(x: f32) -> f32 {
+    x.round()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn roundf(x: f32) -> f32 {
+    libm::roundf(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn ceilf(x: f32) -> f32 {
+    x.ceil()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn ceilf(x: f32) -> f32 {
+    libm::ceilf(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn floorf(x: f32) -> f32 {
+    x.floor()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn floorf(x: f32) -> f32 {
+    libm::floorf(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn powi(x: f32, n: i32) -> f32 {
+    x.powi(n)
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn powi(x: f32, n: i32) -> f32 {
+    libm::powf(x, n as f32)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn log2f(x: f32) -> f32 {
+    x.log2()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn log2f(x: f32) -> f32 {
+    libm::log2f(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn log10f(x: f32) -> f32 {
+    x.log10()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn log10f(x: f32) -> f32 {
+    libm::log10f(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn powf(x: f32, y: f32) -> f32 {
+    x.powf(y)
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn powf(x: f32, y: f32) -> f32 {
+    libm::powf(x, y)
+}
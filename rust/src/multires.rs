@@ -0,0 +1,247 @@
+//! Multi-Resolution FFT Filterbank
+//!
+//! An alternative to the [`crate::gammatone::GammatoneFilterbank`] that
+//! estimates the same per-band envelope from an overlapping short-time
+//! Fourier transform instead of IIR resonators. Trades the gammatone
+//! path's constant low latency for better low-frequency resolution,
+//! which suits offline or music analysis more than real-time metering.
+//!
+//! Each band pulls its magnitude from whichever of [`FFT_SIZES`] has bin
+//! spacing closest to that band's bandwidth — low, narrow bands read from
+//! the largest (finest-resolution) transform, wide high bands from the
+//! smallest.
+
+use crate::fft::{fft_radix2, hann_window, Complex32};
+use crate::scales::BandInfo;
+
+/// FFT sizes used for the multi-resolution transform, largest (finest
+/// frequency resolution, coarsest time resolution) first
+const FFT_SIZES: [usize; 2] = [4096, 1024];
+
+/// A bank that maps an overlapping multi-resolution STFT onto [`BandInfo`] bands
+#[derive(Debug, Clone)]
+pub struct MultiResFftFilterbank {
+    sample_rate: f32,
+    bands: Vec<BandInfo>,
+    /// Index into `FFT_SIZES` chosen for each band
+    band_fft_index: Vec<usize>,
+    /// `[lo, hi)` bin range within that FFT size for each band
+    band_bin_range: Vec<(usize, usize)>,
+    /// One ring buffer per FFT size, each exactly that size long
+    ring_buffers: Vec<Vec<f32>>,
+    write_pos: Vec<usize>,
+    /// One precomputed Hann window per FFT size
+    windows: Vec<Vec<f32>>,
+    magnitudes: Vec<f32>,
+    smoothed_magnitudes: Vec<f32>,
+    smooth_coeff: f32,
+}
+
+impl MultiResFftFilterbank {
+    /// Build a filterbank mapping `bands` onto the multi-resolution STFT
+    #[must_use]
+    pub fn new(bands: Vec<BandInfo>, sample_rate: f32, smoothing_ms: f32) -> Self {
+        let band_fft_index: Vec<usize> = bands
+            .iter()
+            .map(|band| best_fft_index(band.bandwidth_hz, sample_rate))
+            .collect();
+
+        let band_bin_range: Vec<(usize, usize)> = bands
+            .iter()
+            .zip(&band_fft_index)
+            .map(|(band, &fft_index)| bin_range(band, FFT_SIZES[fft_index], sample_rate))
+            .collect();
+
+        let ring_buffers = FFT_SIZES.iter().map(|&size| vec![0.0; size]).collect();
+        let windows = FFT_SIZES.iter().map(|&size| hann_window(size)).collect();
+
+        let smooth_coeff = if smoothing_ms > 0.0 {
+            let tau = smoothing_ms / 1000.0;
+            (-1.0 / (tau * sample_rate)).exp()
+        } else {
+            0.0
+        };
+
+        let num_bands = bands.len();
+        Self {
+            sample_rate,
+            bands,
+            band_fft_index,
+            band_bin_range,
+            ring_buffers,
+            write_pos: vec![0; FFT_SIZES.len()],
+            windows,
+            magnitudes: vec![0.0; num_bands],
+            smoothed_magnitudes: vec![0.0; num_bands],
+            smooth_coeff,
+        }
+    }
+
+    /// Reset all ring buffers and envelope state
+    pub fn reset(&mut self) {
+        for buffer in &mut self.ring_buffers {
+            buffer.fill(0.0);
+        }
+        self.write_pos.fill(0);
+        self.magnitudes.fill(0.0);
+        self.smoothed_magnitudes.fill(0.0);
+    }
+
+    /// Push a block of samples into every ring buffer and recompute the
+    /// per-band envelope from the resulting multi-resolution spectrum
+    pub fn process_block(&mut self, input: &[f32]) {
+        for &sample in input {
+            for (buffer, pos) in self.ring_buffers.iter_mut().zip(self.write_pos.iter_mut()) {
+                buffer[*pos] = sample;
+                *pos = (*pos + 1) % buffer.len();
+            }
+        }
+        self.update_magnitudes(input.len());
+    }
+
+    fn update_magnitudes(&mut self, num_samples: usize) {
+        // Run each FFT size's spectrum once, even if several bands share it
+        let spectra: Vec<Vec<f32>> = (0..FFT_SIZES.len()).map(|i| self.spectrum(i)).collect();
+
+        // `smooth_coeff` is per sample; one update spans the whole block
+        let coeff = self.smooth_coeff.powi(num_samples as i32);
+
+        for i in 0..self.bands.len() {
+            let spectrum = &spectra[self.band_fft_index[i]];
+            let (lo, hi) = self.band_bin_range[i];
+            let sum: f32 = spectrum[lo..hi].iter().sum();
+            let mag = sum / (hi - lo) as f32;
+
+            self.magnitudes[i] = mag;
+            self.smoothed_magnitudes[i] = if self.smooth_coeff > 0.0 {
+                coeff * self.smoothed_magnitudes[i] + (1.0 - coeff) * mag
+            } else {
+                mag
+            };
+        }
+    }
+
+    /// Magnitude spectrum (bins `0..size/2`) for FFT size index `fft_index`,
+    /// windowed and unrolled starting from the oldest sample in the ring
+    fn spectrum(&self, fft_index: usize) -> Vec<f32> {
+        let ring = &self.ring_buffers[fft_index];
+        let window = &self.windows[fft_index];
+        let pos = self.write_pos[fft_index];
+        let size = ring.len();
+
+        let mut buffer: Vec<Complex32> = (0..size)
+            .map(|i| {
+                let sample = ring[(pos + i) % size];
+                Complex32 {
+                    re: sample * window[i],
+                    im: 0.0,
+                }
+            })
+            .collect();
+
+        fft_radix2(&mut buffer);
+        buffer[..size / 2].iter().map(|c| c.magnitude()).collect()
+    }
+
+    /// Get the number of bands
+    #[must_use]
+    pub fn num_bands(&self) -> usize {
+        self.bands.len()
+    }
+
+    /// Get band information
+    #[must_use]
+    pub fn bands(&self) -> &[BandInfo] {
+        &self.bands
+    }
+
+    /// Get the smoothed envelope
+    #[must_use]
+    pub fn envelope(&self) -> &[f32] {
+        &self.smoothed_magnitudes
+    }
+
+    /// Get the smoothed envelope in decibels
+    #[must_use]
+    pub fn envelope_db(&self, min_db: f32) -> Vec<f32> {
+        self.smoothed_magnitudes
+            .iter()
+            .map(|&mag| if mag > 0.0 { 20.0 * mag.log10() } else { min_db })
+            .collect()
+    }
+
+    /// Get the sample rate in Hz
+    #[must_use]
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+}
+
+/// Pick the FFT size whose bin spacing (`sample_rate / size`) most closely
+/// matches `bandwidth_hz`, so each band reads from the resolution that best
+/// fits it rather than always the largest or smallest transform
+fn best_fft_index(bandwidth_hz: f32, sample_rate: f32) -> usize {
+    FFT_SIZES
+        .iter()
+        .enumerate()
+        .min_by(|(_, &a), (_, &b)| {
+            let spacing_a = (sample_rate / a as f32 - bandwidth_hz).abs();
+            let spacing_b = (sample_rate / b as f32 - bandwidth_hz).abs();
+            spacing_a.partial_cmp(&spacing_b).unwrap()
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// Bin range `[lo, hi)` covering `band`'s edges at the given FFT size,
+/// clamped to the valid spectrum and guaranteed non-empty
+fn bin_range(band: &BandInfo, fft_size: usize, sample_rate: f32) -> (usize, usize) {
+    let bin_hz = sample_rate / fft_size as f32;
+    let max_bin = fft_size / 2;
+
+    let lo = ((band.low_hz / bin_hz).floor() as usize).min(max_bin.saturating_sub(1));
+    let hi = ((band.high_hz / bin_hz).ceil() as usize)
+        .max(lo + 1)
+        .min(max_bin);
+    (lo, hi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scales::{generate_bands, Scale};
+    use crate::test_util::sine;
+
+    #[test]
+    fn test_multires_filterbank_creation() {
+        let bands = generate_bands(Scale::ERB, 40, 20.0, 20000.0);
+        let fb = MultiResFftFilterbank::new(bands, 48000.0, 5.0);
+        assert_eq!(fb.num_bands(), 40);
+    }
+
+    #[test]
+    fn test_multires_filterbank_1khz_sine() {
+        let bands = generate_bands(Scale::ERB, 40, 20.0, 20000.0);
+        let mut fb = MultiResFftFilterbank::new(bands, 48000.0, 5.0);
+
+        let signal = sine(1000.0, 48000.0, 8192);
+        fb.process_block(&signal);
+
+        let (peak_band, _) = fb
+            .envelope()
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .unwrap();
+
+        let peak_freq = fb.bands()[peak_band].center_hz;
+        assert!(peak_freq > 700.0 && peak_freq < 1400.0, "peak at {peak_freq} Hz");
+    }
+
+    #[test]
+    fn test_low_bands_use_the_largest_fft() {
+        let bands = generate_bands(Scale::ERB, 40, 20.0, 20000.0);
+        let fb = MultiResFftFilterbank::new(bands, 48000.0, 5.0);
+        assert_eq!(fb.band_fft_index[0], 0, "lowest band should pick the 4096-pt FFT");
+    }
+}
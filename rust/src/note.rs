@@ -0,0 +1,144 @@
+//! Equal-Temperament Note Mapping
+//!
+//! Maps detected frequencies onto the 12-tone equal-temperament grid, with
+//! a configurable tuning reference (`a4_hz`, default 440.0) so a tuner UI
+//! can show how sharp or flat a pitch is in cents.
+
+use crate::mathcompat::{log2f, powf, roundf};
+
+/// One of the 12 pitch classes of equal temperament
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteName {
+    C,
+    CSharp,
+    D,
+    DSharp,
+    E,
+    F,
+    FSharp,
+    G,
+    GSharp,
+    A,
+    ASharp,
+    B,
+}
+
+impl NoteName {
+    fn from_index(index: i32) -> NoteName {
+        match index.rem_euclid(12) {
+            0 => NoteName::C,
+            1 => NoteName::CSharp,
+            2 => NoteName::D,
+            3 => NoteName::DSharp,
+            4 => NoteName::E,
+            5 => NoteName::F,
+            6 => NoteName::FSharp,
+            7 => NoteName::G,
+            8 => NoteName::GSharp,
+            9 => NoteName::A,
+            10 => NoteName::ASharp,
+            _ => NoteName::B,
+        }
+    }
+
+    fn semitone_index(self) -> i32 {
+        match self {
+            NoteName::C => 0,
+            NoteName::CSharp => 1,
+            NoteName::D => 2,
+            NoteName::DSharp => 3,
+            NoteName::E => 4,
+            NoteName::F => 5,
+            NoteName::FSharp => 6,
+            NoteName::G => 7,
+            NoteName::GSharp => 8,
+            NoteName::A => 9,
+            NoteName::ASharp => 10,
+            NoteName::B => 11,
+        }
+    }
+}
+
+/// Default tuning reference: A4 = 440 Hz
+pub const DEFAULT_A4_HZ: f32 = 440.0;
+
+/// Convert a frequency in Hz to the nearest equal-temperament note
+///
+/// Returns the note name, its octave (scientific pitch notation, where
+/// A4 is in octave 4), and the signed deviation in cents from that note's
+/// exact frequency (positive means sharp, negative means flat).
+#[must_use]
+pub fn hz_to_note(hz: f32, a4_hz: f32) -> (NoteName, i32, f32) {
+    let midi = 69.0 + 12.0 * log2f(hz / a4_hz);
+    let nearest_midi = roundf(midi);
+
+    let octave = (nearest_midi as i32).div_euclid(12) - 1;
+    let name = NoteName::from_index(nearest_midi as i32);
+
+    let nearest_hz = note_to_hz(name, octave, a4_hz);
+    let cents = 1200.0 * log2f(hz / nearest_hz);
+
+    (name, octave, cents)
+}
+
+/// Convert a note name and octave (scientific pitch notation) to Hz under
+/// the given tuning reference
+#[must_use]
+pub fn note_to_hz(name: NoteName, octave: i32, a4_hz: f32) -> f32 {
+    let midi = (octave + 1) * 12 + name.semitone_index();
+    a4_hz * powf(2.0, (midi - 69) as f32 / 12.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_equal(a: f32, b: f32, tolerance: f32) -> bool {
+        (a - b).abs() < tolerance
+    }
+
+    #[test]
+    fn test_hz_to_note_a4_is_exact() {
+        let (name, octave, cents) = hz_to_note(440.0, DEFAULT_A4_HZ);
+        assert_eq!(name, NoteName::A);
+        assert_eq!(octave, 4);
+        assert!(approx_equal(cents, 0.0, 0.1));
+    }
+
+    #[test]
+    fn test_hz_to_note_middle_c() {
+        let (name, octave, _cents) = hz_to_note(261.63, DEFAULT_A4_HZ);
+        assert_eq!(name, NoteName::C);
+        assert_eq!(octave, 4);
+    }
+
+    #[test]
+    fn test_hz_to_note_reports_sharp_and_flat_cents() {
+        let (_, _, sharp_cents) = hz_to_note(445.0, DEFAULT_A4_HZ);
+        assert!(sharp_cents > 0.0);
+
+        let (_, _, flat_cents) = hz_to_note(435.0, DEFAULT_A4_HZ);
+        assert!(flat_cents < 0.0);
+    }
+
+    #[test]
+    fn test_note_to_hz_roundtrip() {
+        for octave in 2..6 {
+            for midi_offset in 0..12 {
+                let name = NoteName::from_index(midi_offset);
+                let hz = note_to_hz(name, octave, DEFAULT_A4_HZ);
+                let (back_name, back_octave, cents) = hz_to_note(hz, DEFAULT_A4_HZ);
+                assert_eq!(back_name, name);
+                assert_eq!(back_octave, octave);
+                assert!(approx_equal(cents, 0.0, 0.1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_note_to_hz_supports_alternate_tuning() {
+        // A4 = 432Hz tuning
+        let hz = note_to_hz(NoteName::A, 4, 432.0);
+        assert!(approx_equal(hz, 432.0, 0.01));
+    }
+}
@@ -0,0 +1,275 @@
+//! Pitch Detection
+//!
+//! Monophonic fundamental-frequency estimators operating on the same mono
+//! input block the [`crate::Analyser`] already processes.
+
+//=============================================================================
+// McLeod Pitch Method (MPM)
+//=============================================================================
+
+/// McLeod's "k" threshold: accept the first key maximum within this fraction
+/// of the global maximum
+const DEFAULT_THRESHOLD: f32 = 0.9;
+/// Below this clarity the result is treated as silence/noise rather than a pitch
+const CONFIDENCE_FLOOR: f32 = 0.3;
+
+/// Result of a pitch detection pass
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PitchEstimate {
+    /// Estimated fundamental frequency in Hz
+    pub frequency_hz: f32,
+    /// Confidence of the estimate, 0..1 (the NSDF peak height)
+    pub clarity: f32,
+}
+
+/// Detect the fundamental frequency of `signal` using the McLeod Pitch
+/// Method (MPM), returning `None` below the confidence floor so silence or
+/// noise doesn't produce a spurious pitch
+pub fn mcleod_pitch(signal: &[f32], sample_rate: f32) -> Option<PitchEstimate> {
+    mcleod_pitch_with_threshold(signal, sample_rate, DEFAULT_THRESHOLD)
+}
+
+/// Like [`mcleod_pitch`], with an explicit key-maximum threshold (McLeod's `k`)
+pub fn mcleod_pitch_with_threshold(
+    signal: &[f32],
+    sample_rate: f32,
+    threshold: f32,
+) -> Option<PitchEstimate> {
+    let max_lag = signal.len() / 2;
+    if max_lag < 2 {
+        return None;
+    }
+
+    let nsdf = normalized_square_difference(signal, max_lag);
+    let key_maxima = find_key_maxima(&nsdf);
+    let global_max = key_maxima
+        .iter()
+        .map(|&(_, v)| v)
+        .fold(f32::MIN, f32::max);
+
+    let &(lag, _) = key_maxima.iter().find(|&&(_, v)| v > threshold * global_max)?;
+
+    let (refined_lag, clarity) = parabolic_interpolate(&nsdf, lag);
+    if refined_lag <= 0.0 || clarity < CONFIDENCE_FLOOR {
+        return None;
+    }
+
+    Some(PitchEstimate {
+        frequency_hz: sample_rate / refined_lag,
+        clarity: clarity.clamp(0.0, 1.0),
+    })
+}
+
+/// Normalized square difference function (NSDF):
+/// `2 * sum(x_j * x_{j+tau}) / sum(x_j^2 + x_{j+tau}^2)` for each lag `tau`
+fn normalized_square_difference(signal: &[f32], max_lag: usize) -> Vec<f32> {
+    let n = signal.len();
+    let mut nsdf = vec![0.0; max_lag];
+
+    for (tau, slot) in nsdf.iter_mut().enumerate() {
+        let mut acf = 0.0;
+        let mut energy = 0.0;
+        for j in 0..(n - tau) {
+            acf += signal[j] * signal[j + tau];
+            energy += signal[j] * signal[j] + signal[j + tau] * signal[j + tau];
+        }
+        *slot = if energy > 0.0 { 2.0 * acf / energy } else { 0.0 };
+    }
+
+    nsdf
+}
+
+/// Positive-going zero crossings of the NSDF bound a series of lobes; within
+/// each, the local maximum is a "key maximum" candidate for the period
+fn find_key_maxima(nsdf: &[f32]) -> Vec<(usize, f32)> {
+    let mut maxima = Vec::new();
+    let mut i = 1;
+
+    while i < nsdf.len() {
+        if nsdf[i - 1] < 0.0 && nsdf[i] >= 0.0 {
+            let start = i;
+            let mut end = i;
+            while end < nsdf.len() && nsdf[end] >= 0.0 {
+                end += 1;
+            }
+
+            let (max_idx, max_val) = (start..end)
+                .map(|k| (k, nsdf[k]))
+                .fold((start, nsdf[start]), |best, cur| {
+                    if cur.1 > best.1 {
+                        cur
+                    } else {
+                        best
+                    }
+                });
+            maxima.push((max_idx, max_val));
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+
+    maxima
+}
+
+/// Refine a lag estimate with parabolic interpolation over its three
+/// surrounding NSDF samples, returning the sub-sample lag and peak height
+fn parabolic_interpolate(nsdf: &[f32], lag: usize) -> (f32, f32) {
+    if lag == 0 || lag + 1 >= nsdf.len() {
+        return (lag as f32, nsdf[lag]);
+    }
+
+    let (y0, y1, y2) = (nsdf[lag - 1], nsdf[lag], nsdf[lag + 1]);
+    let denom = y0 - 2.0 * y1 + y2;
+    if denom.abs() < 1e-12 {
+        return (lag as f32, y1);
+    }
+
+    let delta = 0.5 * (y0 - y2) / denom;
+    (lag as f32 + delta, y1 - 0.25 * (y0 - y2) * delta)
+}
+
+//=============================================================================
+// Autocorrelation Pitch Detector
+//=============================================================================
+
+/// Below this amplitude (after DC removal) the signal is treated as silence
+const SILENCE_THRESHOLD: f32 = 0.05;
+/// Reject a refined peak weaker than this fraction of `r[0]` as noise
+const AUTOCORR_CONFIDENCE_FLOOR: f32 = 0.3;
+/// Bisection steps used to refine the period to sub-sample precision
+const REFINEMENT_STEPS: u32 = 4;
+
+/// Detect the fundamental frequency of `signal` via time-domain
+/// autocorrelation, a cheaper (if less precise near noise) alternative to
+/// [`mcleod_pitch`]. The detected Hz value plugs directly into the crate's
+/// existing scale conversions (`hz_to_bark`, `hz_to_erb`, `hz_to_mel`, ...).
+///
+/// Removes the DC offset, then finds the first lag past the central
+/// autocorrelation lobe (the first negative-going crossing) and takes the
+/// highest peak after it as the period. That peak is refined to sub-sample
+/// precision by bisecting `[period - 0.5, period + 0.5]` against
+/// linearly-interpolated correlation scores.
+///
+/// Returns `None` for silence, a signal whose autocorrelation never goes
+/// negative (no clear periodicity), or a refined peak too weak relative to
+/// `r[0]` to trust.
+pub fn fundamental_frequency(signal: &[f32], sample_rate: f32) -> Option<f32> {
+    let n = signal.len();
+    if n < 2 {
+        return None;
+    }
+
+    let mean = signal.iter().sum::<f32>() / n as f32;
+    let centered: Vec<f32> = signal.iter().map(|&s| s - mean).collect();
+
+    if centered.iter().all(|&s| s.abs() < SILENCE_THRESHOLD) {
+        return None;
+    }
+
+    let autocorr = |offset: usize| -> f32 {
+        centered[..n - offset]
+            .iter()
+            .zip(&centered[offset..])
+            .map(|(&a, &b)| a * b)
+            .sum()
+    };
+
+    let r0 = autocorr(0);
+    let first_negative = (1..n).find(|&offset| autocorr(offset) < 0.0)?;
+
+    let (peak_offset, _) = (first_negative..n)
+        .map(|offset| (offset, autocorr(offset)))
+        .fold((first_negative, f32::MIN), |best, cur| {
+            if cur.1 > best.1 {
+                cur
+            } else {
+                best
+            }
+        });
+
+    let interpolated = |lag: f32| -> f32 {
+        let lo = lag.floor();
+        let hi = lag.ceil();
+        if lo == hi {
+            return autocorr(lo as usize);
+        }
+        let frac = lag - lo;
+        autocorr(lo as usize) * (1.0 - frac) + autocorr(hi as usize) * frac
+    };
+
+    let mut lo = peak_offset as f32 - 0.5;
+    let mut hi = peak_offset as f32 + 0.5;
+    for _ in 0..REFINEMENT_STEPS {
+        let mid = (lo + hi) / 2.0;
+        let left_mid = (lo + mid) / 2.0;
+        let right_mid = (mid + hi) / 2.0;
+        if interpolated(left_mid) > interpolated(right_mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+
+    let refined_period = (lo + hi) / 2.0;
+    let refined_value = interpolated(refined_period);
+
+    if r0 <= 0.0 || refined_value < AUTOCORR_CONFIDENCE_FLOOR * r0 {
+        return None;
+    }
+
+    Some(sample_rate / refined_period)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::sine;
+
+    #[test]
+    fn test_mcleod_detects_sine_pitch() {
+        let sample_rate = 48000.0;
+        let signal = sine(220.0, sample_rate, 2048);
+
+        let estimate = mcleod_pitch(&signal, sample_rate).expect("should detect a pitch");
+        assert!(
+            (estimate.frequency_hz - 220.0).abs() < 5.0,
+            "got {} Hz",
+            estimate.frequency_hz
+        );
+        assert!(estimate.clarity > 0.9);
+    }
+
+    #[test]
+    fn test_mcleod_silence_returns_none() {
+        let signal = vec![0.0f32; 2048];
+        assert_eq!(mcleod_pitch(&signal, 48000.0), None);
+    }
+
+    #[test]
+    fn test_mcleod_short_signal_returns_none() {
+        let signal = vec![0.0f32; 2];
+        assert_eq!(mcleod_pitch(&signal, 48000.0), None);
+    }
+
+    #[test]
+    fn test_fundamental_frequency_detects_sine_pitch() {
+        let sample_rate = 48000.0;
+        let signal = sine(220.0, sample_rate, 2048);
+
+        let hz = fundamental_frequency(&signal, sample_rate).expect("should detect a pitch");
+        assert!((hz - 220.0).abs() < 5.0, "got {} Hz", hz);
+    }
+
+    #[test]
+    fn test_fundamental_frequency_silence_returns_none() {
+        let signal = vec![0.0f32; 2048];
+        assert_eq!(fundamental_frequency(&signal, 48000.0), None);
+    }
+
+    #[test]
+    fn test_fundamental_frequency_short_signal_returns_none() {
+        let signal = vec![0.0f32; 1];
+        assert_eq!(fundamental_frequency(&signal, 48000.0), None);
+    }
+}
@@ -5,6 +5,8 @@
 //! - ERB (equivalent rectangular bandwidth)
 //! - Mel (pitch perception)
 
+use crate::mathcompat::{ceilf, floorf, log10f, log2f, powf, sqrtf};
+
 //=============================================================================
 // Bark Scale (Critical Bands)
 // Based on Traunmüller (1990) formula
@@ -27,7 +29,7 @@ pub fn bark_to_hz(bark: f32) -> f32 {
 /// Zwicker & Terhardt (1980)
 #[inline]
 pub fn critical_bandwidth(hz: f32) -> f32 {
-    25.0 + 75.0 * (1.0 + 1.4 * (hz / 1000.0) * (hz / 1000.0)).powf(0.69)
+    25.0 + 75.0 * powf(1.0 + 1.4 * (hz / 1000.0) * (hz / 1000.0), 0.69)
 }
 
 //=============================================================================
@@ -44,13 +46,13 @@ pub fn erb_bandwidth(hz: f32) -> f32 {
 /// Convert frequency in Hz to ERB-rate scale
 #[inline]
 pub fn hz_to_erb(hz: f32) -> f32 {
-    21.4 * (4.37 * hz / 1000.0 + 1.0).log10()
+    21.4 * log10f(4.37 * hz / 1000.0 + 1.0)
 }
 
 /// Convert ERB-rate scale to frequency in Hz
 #[inline]
 pub fn erb_to_hz(erb: f32) -> f32 {
-    (10.0_f32.powf(erb / 21.4) - 1.0) * 1000.0 / 4.37
+    (powf(10.0, erb / 21.4) - 1.0) * 1000.0 / 4.37
 }
 
 //=============================================================================
@@ -61,13 +63,13 @@ pub fn erb_to_hz(erb: f32) -> f32 {
 /// Convert frequency in Hz to Mel scale
 #[inline]
 pub fn hz_to_mel(hz: f32) -> f32 {
-    2595.0 * (1.0 + hz / 700.0).log10()
+    2595.0 * log10f(1.0 + hz / 700.0)
 }
 
 /// Convert Mel scale to frequency in Hz
 #[inline]
 pub fn mel_to_hz(mel: f32) -> f32 {
-    700.0 * (10.0_f32.powf(mel / 2595.0) - 1.0)
+    700.0 * (powf(10.0, mel / 2595.0) - 1.0)
 }
 
 //=============================================================================
@@ -88,6 +90,12 @@ pub enum Scale {
     ERB,
     /// Pitch perception
     Mel,
+    /// IEC base-two octave/fractional-octave spacing, anchored at 1 kHz.
+    /// `fraction` is the number of bands per octave (1, 3, 6, 12, ...).
+    Octave {
+        /// Bands per octave (1 = full octave, 3 = third-octave, ...)
+        fraction: u32,
+    },
 }
 
 //=============================================================================
@@ -108,6 +116,7 @@ pub struct BandInfo {
 }
 
 /// Generate frequency bands spaced according to the given scale
+#[cfg(feature = "std")]
 pub fn generate_bands(scale: Scale, num_bands: usize, min_hz: f32, max_hz: f32) -> Vec<BandInfo> {
     let mut bands = Vec::with_capacity(num_bands);
 
@@ -127,16 +136,16 @@ pub fn generate_bands(scale: Scale, num_bands: usize, min_hz: f32, max_hz: f32)
         }
 
         Scale::Log => {
-            let log_min = min_hz.log2();
-            let log_max = max_hz.log2();
+            let log_min = log2f(min_hz);
+            let log_max = log2f(max_hz);
             let step = (log_max - log_min) / num_bands as f32;
             for i in 0..num_bands {
-                let low_hz = 2.0_f32.powf(log_min + i as f32 * step);
-                let high_hz = 2.0_f32.powf(log_min + (i + 1) as f32 * step);
+                let low_hz = powf(2.0, log_min + i as f32 * step);
+                let high_hz = powf(2.0, log_min + (i + 1) as f32 * step);
                 bands.push(BandInfo {
                     low_hz,
                     high_hz,
-                    center_hz: (low_hz * high_hz).sqrt(), // Geometric mean
+                    center_hz: sqrtf(low_hz * high_hz), // Geometric mean
                     bandwidth_hz: high_hz - low_hz,
                 });
             }
@@ -195,9 +204,322 @@ pub fn generate_bands(scale: Scale, num_bands: usize, min_hz: f32, max_hz: f32)
                 });
             }
         }
+
+        // Bands-per-octave spacing is fixed by the standard (anchored at 1 kHz),
+        // so `num_bands` doesn't apply here; the band count is whatever fits
+        // the requested range.
+        Scale::Octave { fraction } => {
+            let b = fraction as f32;
+            let x_min = ceilf(b * log2f(min_hz / 1000.0)) as i32;
+            let x_max = floorf(b * log2f(max_hz / 1000.0)) as i32;
+
+            for x in x_min..=x_max {
+                let center_hz = 1000.0 * powf(2.0, x as f32 / b);
+                let low_hz = center_hz * powf(2.0, -1.0 / (2.0 * b));
+                let high_hz = center_hz * powf(2.0, 1.0 / (2.0 * b));
+                bands.push(BandInfo {
+                    low_hz,
+                    high_hz,
+                    center_hz,
+                    bandwidth_hz: high_hz - low_hz,
+                });
+            }
+        }
+    }
+
+    bands
+}
+
+/// Like [`generate_bands`], but writes into a caller-supplied slice instead
+/// of allocating a `Vec` — the allocation-free entry point used by
+/// [`crate::embedded::EmbeddedFilterbank`].
+///
+/// Fills at most `bands.len()` entries and returns the number written. For
+/// every scale except `Octave`, the slice length is the band count and the
+/// whole slice is filled; `Octave`'s band count is fixed by the standard, so
+/// it may fill fewer entries than the slice holds.
+pub fn generate_bands_into(bands: &mut [BandInfo], scale: Scale, min_hz: f32, max_hz: f32) -> usize {
+    let num_bands = bands.len();
+
+    match scale {
+        Scale::Linear => {
+            let step = (max_hz - min_hz) / num_bands as f32;
+            for (i, slot) in bands.iter_mut().enumerate() {
+                let low_hz = min_hz + i as f32 * step;
+                let high_hz = low_hz + step;
+                *slot = BandInfo {
+                    low_hz,
+                    high_hz,
+                    center_hz: (low_hz + high_hz) / 2.0,
+                    bandwidth_hz: step,
+                };
+            }
+            num_bands
+        }
+
+        Scale::Log => {
+            let log_min = log2f(min_hz);
+            let log_max = log2f(max_hz);
+            let step = (log_max - log_min) / num_bands as f32;
+            for (i, slot) in bands.iter_mut().enumerate() {
+                let low_hz = powf(2.0, log_min + i as f32 * step);
+                let high_hz = powf(2.0, log_min + (i + 1) as f32 * step);
+                *slot = BandInfo {
+                    low_hz,
+                    high_hz,
+                    center_hz: sqrtf(low_hz * high_hz),
+                    bandwidth_hz: high_hz - low_hz,
+                };
+            }
+            num_bands
+        }
+
+        Scale::Bark => {
+            let bark_min = hz_to_bark(min_hz);
+            let bark_max = hz_to_bark(max_hz);
+            let step = (bark_max - bark_min) / num_bands as f32;
+            for (i, slot) in bands.iter_mut().enumerate() {
+                let bark_low = bark_min + i as f32 * step;
+                let bark_high = bark_min + (i + 1) as f32 * step;
+                let low_hz = bark_to_hz(bark_low);
+                let high_hz = bark_to_hz(bark_high);
+                *slot = BandInfo {
+                    low_hz,
+                    high_hz,
+                    center_hz: bark_to_hz((bark_low + bark_high) / 2.0),
+                    bandwidth_hz: high_hz - low_hz,
+                };
+            }
+            num_bands
+        }
+
+        Scale::ERB => {
+            let erb_min = hz_to_erb(min_hz);
+            let erb_max = hz_to_erb(max_hz);
+            let step = (erb_max - erb_min) / num_bands as f32;
+            for (i, slot) in bands.iter_mut().enumerate() {
+                let erb_low = erb_min + i as f32 * step;
+                let erb_high = erb_min + (i + 1) as f32 * step;
+                let low_hz = erb_to_hz(erb_low);
+                let high_hz = erb_to_hz(erb_high);
+                *slot = BandInfo {
+                    low_hz,
+                    high_hz,
+                    center_hz: erb_to_hz((erb_low + erb_high) / 2.0),
+                    bandwidth_hz: high_hz - low_hz,
+                };
+            }
+            num_bands
+        }
+
+        Scale::Mel => {
+            let mel_min = hz_to_mel(min_hz);
+            let mel_max = hz_to_mel(max_hz);
+            let step = (mel_max - mel_min) / num_bands as f32;
+            for (i, slot) in bands.iter_mut().enumerate() {
+                let mel_low = mel_min + i as f32 * step;
+                let mel_high = mel_min + (i + 1) as f32 * step;
+                let low_hz = mel_to_hz(mel_low);
+                let high_hz = mel_to_hz(mel_high);
+                *slot = BandInfo {
+                    low_hz,
+                    high_hz,
+                    center_hz: mel_to_hz((mel_low + mel_high) / 2.0),
+                    bandwidth_hz: high_hz - low_hz,
+                };
+            }
+            num_bands
+        }
+
+        Scale::Octave { fraction } => {
+            let b = fraction as f32;
+            let x_min = ceilf(b * log2f(min_hz / 1000.0)) as i32;
+            let x_max = floorf(b * log2f(max_hz / 1000.0)) as i32;
+
+            let mut written = 0;
+            for x in x_min..=x_max {
+                if written >= bands.len() {
+                    break;
+                }
+                let center_hz = 1000.0 * powf(2.0, x as f32 / b);
+                let low_hz = center_hz * powf(2.0, -1.0 / (2.0 * b));
+                let high_hz = center_hz * powf(2.0, 1.0 / (2.0 * b));
+                bands[written] = BandInfo {
+                    low_hz,
+                    high_hz,
+                    center_hz,
+                    bandwidth_hz: high_hz - low_hz,
+                };
+                written += 1;
+            }
+            written
+        }
     }
+}
+
+/// Triangular filterbank weights over FFT bins, built from equally-spaced
+/// centers in the chosen perceptual domain — the standard construction
+/// used for mel/ERB/Bark feature pipelines such as MFCCs.
+///
+/// Returns `num_filters` rows, each `fft_size / 2 + 1` weights long. Filter
+/// `i` rises linearly from perceptual center `i` to `i + 1` and falls from
+/// `i + 1` to `i + 2`, where the centers are `num_filters + 2` points
+/// equally spaced in the scale's domain across `[min_hz, max_hz]` and
+/// converted back to Hz. `Scale::Log` and `Scale::Octave` both space
+/// centers by `log2(hz)`, since octave spacing is log-based too. Pass
+/// `normalize = true` for Slaney-style area normalization
+/// (`2 / (f[i+2] - f[i])`), which keeps narrow and wide filters at
+/// comparable energy.
+#[cfg(feature = "std")]
+pub fn filterbank(
+    scale: Scale,
+    num_filters: usize,
+    min_hz: f32,
+    max_hz: f32,
+    fft_size: usize,
+    sample_rate: f32,
+    normalize: bool,
+) -> Vec<Vec<f32>> {
+    let to_scale = |hz: f32| -> f32 {
+        match scale {
+            Scale::Mel => hz_to_mel(hz),
+            Scale::Bark => hz_to_bark(hz),
+            Scale::ERB => hz_to_erb(hz),
+            Scale::Log | Scale::Octave { .. } => log2f(hz),
+            Scale::Linear => hz,
+        }
+    };
+    let from_scale = |val: f32| -> f32 {
+        match scale {
+            Scale::Mel => mel_to_hz(val),
+            Scale::Bark => bark_to_hz(val),
+            Scale::ERB => erb_to_hz(val),
+            Scale::Log | Scale::Octave { .. } => powf(2.0, val),
+            Scale::Linear => val,
+        }
+    };
+
+    let scale_min = to_scale(min_hz);
+    let scale_max = to_scale(max_hz);
+    let step = (scale_max - scale_min) / (num_filters + 1) as f32;
+
+    let centers_hz: Vec<f32> = (0..num_filters + 2)
+        .map(|i| from_scale(scale_min + i as f32 * step))
+        .collect();
+
+    let num_bins = fft_size / 2 + 1;
+    let bin_hz = sample_rate / fft_size as f32;
+
+    (0..num_filters)
+        .map(|i| {
+            let (f_lo, f_mid, f_hi) = (centers_hz[i], centers_hz[i + 1], centers_hz[i + 2]);
+            let norm = if normalize { 2.0 / (f_hi - f_lo) } else { 1.0 };
+
+            (0..num_bins)
+                .map(|k| {
+                    let f = k as f32 * bin_hz;
+                    let weight = if f <= f_lo || f >= f_hi {
+                        0.0
+                    } else if f <= f_mid {
+                        (f - f_lo) / (f_mid - f_lo)
+                    } else {
+                        (f_hi - f) / (f_hi - f_mid)
+                    };
+                    weight * norm
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// How [`band_energies`] combines the FFT bins that fall inside a band
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BandAggregation {
+    /// Sum of all bin values in the band
+    Sum,
+    /// Mean of all bin values in the band
+    #[default]
+    Mean,
+    /// Largest bin value in the band
+    Peak,
+}
+
+/// Aggregate a magnitude (or power) spectrum into `bands`, the natural
+/// complement to [`generate_bands`]: bin `k` maps to frequency
+/// `k * sample_rate / (2 * (spectrum.len() - 1))`, and every bin whose
+/// frequency falls in `[band.low_hz, band.high_hz)` is combined per
+/// `aggregation`. Bands with no bins in range get `0.0`. A `spectrum` with
+/// fewer than 2 bins has no well-defined bin spacing, so every band gets `0.0`.
+#[cfg(feature = "std")]
+pub fn band_energies(
+    spectrum: &[f32],
+    sample_rate: f32,
+    bands: &[BandInfo],
+    aggregation: BandAggregation,
+) -> Vec<f32> {
+    if spectrum.len() < 2 {
+        return vec![0.0; bands.len()];
+    }
+
+    let bin_hz = sample_rate / (2.0 * (spectrum.len() - 1) as f32);
 
     bands
+        .iter()
+        .map(|band| {
+            let mut sum = 0.0;
+            let mut peak = 0.0_f32;
+            let mut count = 0usize;
+
+            for (k, &value) in spectrum.iter().enumerate() {
+                let freq = k as f32 * bin_hz;
+                if freq >= band.low_hz && freq < band.high_hz {
+                    sum += value;
+                    peak = peak.max(value);
+                    count += 1;
+                }
+            }
+
+            match aggregation {
+                BandAggregation::Sum => sum,
+                BandAggregation::Mean => {
+                    if count > 0 {
+                        sum / count as f32
+                    } else {
+                        0.0
+                    }
+                }
+                BandAggregation::Peak => peak,
+            }
+        })
+        .collect()
+}
+
+/// Nominal "preferred" band label per IEC 61260, e.g. 31.5, 63, 125, 1000 ("1 k")
+///
+/// Snaps `center_hz` to the nearest preferred number from the R10 series
+/// (1.00, 1.25, 1.6, 2.00, 2.5, 3.15, 4.00, 5.00, 6.3, 8.00) scaled by a
+/// power of ten, which is how octave and third-octave bands are labeled on
+/// measurement equipment.
+pub fn octave_preferred_label(center_hz: f32) -> f32 {
+    const PREFERRED: [f32; 10] = [1.00, 1.25, 1.6, 2.00, 2.5, 3.15, 4.00, 5.00, 6.3, 8.00];
+
+    // Nudge by a hair to avoid floating-point round-down at exact decades
+    // (e.g. log10(1000.0) landing just under 3.0).
+    let decade = floorf(log10f(center_hz) + 1e-4);
+    let mantissa = center_hz / powf(10.0, decade);
+
+    let nearest = PREFERRED
+        .iter()
+        .copied()
+        .min_by(|a, b| {
+            (a - mantissa)
+                .abs()
+                .partial_cmp(&(b - mantissa).abs())
+                .unwrap()
+        })
+        .unwrap();
+
+    nearest * powf(10.0, decade)
 }
 
 #[cfg(test)]
@@ -257,6 +579,87 @@ mod tests {
         assert!(approx_equal(hz_to_mel(1000.0), 1000.0, 50.0));
     }
 
+    #[test]
+    fn test_octave_band_generation_third_octave() {
+        let bands = generate_bands(Scale::Octave { fraction: 3 }, 0, 20.0, 20000.0);
+
+        // 1 kHz should be a band center
+        assert!(bands
+            .iter()
+            .any(|b| approx_equal(b.center_hz, 1000.0, 1.0)));
+
+        // Bands ascend and tile without gaps
+        for i in 1..bands.len() {
+            assert!(bands[i].center_hz > bands[i - 1].center_hz);
+            assert!(approx_equal(bands[i].low_hz, bands[i - 1].high_hz, 0.5));
+        }
+    }
+
+    #[test]
+    fn test_octave_preferred_label() {
+        assert!(approx_equal(octave_preferred_label(1000.0), 1000.0, 1.0));
+        assert!(approx_equal(octave_preferred_label(31.0), 31.5, 1.0));
+        assert!(approx_equal(octave_preferred_label(125.5), 125.0, 2.0));
+    }
+
+    #[test]
+    fn test_filterbank_shape() {
+        let fb = filterbank(Scale::Mel, 26, 20.0, 8000.0, 512, 16000.0, false);
+        assert_eq!(fb.len(), 26);
+        assert_eq!(fb[0].len(), 512 / 2 + 1);
+    }
+
+    #[test]
+    fn test_filterbank_triangles_peak_at_one_when_unnormalized() {
+        let fb = filterbank(Scale::Mel, 10, 20.0, 8000.0, 512, 16000.0, false);
+        for filter in &fb {
+            let peak = filter.iter().cloned().fold(0.0_f32, f32::max);
+            assert!(peak > 0.9 && peak <= 1.0 + 1e-5, "peak weight was {peak}");
+        }
+    }
+
+    #[test]
+    fn test_filterbank_normalized_area_shrinks_wider_filters() {
+        let narrow = filterbank(Scale::Mel, 10, 20.0, 8000.0, 512, 16000.0, true);
+        // Later filters span more Hz (mel spacing widens with frequency), so
+        // their normalized peak weight should be smaller than the first.
+        let first_peak = narrow[0].iter().cloned().fold(0.0_f32, f32::max);
+        let last_peak = narrow.last().unwrap().iter().cloned().fold(0.0_f32, f32::max);
+        assert!(last_peak < first_peak);
+    }
+
+    #[test]
+    fn test_band_energies_sums_matching_bins() {
+        // 9 bins spanning 0..800Hz at 100Hz spacing (sample_rate=1600, size=16 -> 9 bins)
+        let spectrum = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let bands = [
+            BandInfo {
+                low_hz: 0.0,
+                high_hz: 300.0,
+                center_hz: 150.0,
+                bandwidth_hz: 300.0,
+            },
+            BandInfo {
+                low_hz: 300.0,
+                high_hz: 600.0,
+                center_hz: 450.0,
+                bandwidth_hz: 300.0,
+            },
+        ];
+
+        let sums = band_energies(&spectrum, 1600.0, &bands, BandAggregation::Sum);
+        // Bin spacing = 1600 / (2*8) = 100Hz, so band 0 covers bins 0,1,2 (0/100/200Hz)
+        assert!(approx_equal(sums[0], 0.0 + 1.0 + 2.0, 1e-6));
+        // Band 1 covers bins 3,4,5 (300/400/500Hz)
+        assert!(approx_equal(sums[1], 3.0 + 4.0 + 5.0, 1e-6));
+
+        let means = band_energies(&spectrum, 1600.0, &bands, BandAggregation::Mean);
+        assert!(approx_equal(means[0], (0.0 + 1.0 + 2.0) / 3.0, 1e-6));
+
+        let peaks = band_energies(&spectrum, 1600.0, &bands, BandAggregation::Peak);
+        assert!(approx_equal(peaks[1], 5.0, 1e-6));
+    }
+
     #[test]
     fn test_band_generation() {
         let bands = generate_bands(Scale::ERB, 40, 20.0, 20000.0);
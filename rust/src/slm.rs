@@ -0,0 +1,235 @@
+//! Sound Level Meter
+//!
+//! IEC 61672 frequency weighting (A, C, or Z/flat) and exponential
+//! time-weighting (Fast/Slow), applied across the gammatone bands to
+//! produce a single broadband level plus an equivalent-continuous
+//! level (Leq).
+
+use crate::scales::BandInfo;
+
+//=============================================================================
+// Frequency Weighting
+//=============================================================================
+
+/// IEC 61672 frequency weighting curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Weighting {
+    /// A-weighting, approximates human hearing sensitivity at moderate levels
+    #[default]
+    A,
+    /// C-weighting, approximates human hearing sensitivity at high levels
+    C,
+    /// Flat (unweighted) response
+    Z,
+}
+
+impl Weighting {
+    /// Relative gain in dB at the given frequency
+    pub fn gain_db(&self, hz: f32) -> f32 {
+        match self {
+            Weighting::A => a_weighting_db(hz),
+            Weighting::C => c_weighting_db(hz),
+            Weighting::Z => 0.0,
+        }
+    }
+}
+
+/// A-weighting relative response at `hz`, per IEC 61672
+pub fn a_weighting_db(hz: f32) -> f32 {
+    let f2 = hz * hz;
+    let c1 = 20.6 * 20.6;
+    let c2 = 107.7 * 107.7;
+    let c3 = 737.9 * 737.9;
+    let c4 = 12194.0 * 12194.0;
+
+    let numerator = c4 * f2 * f2;
+    let denominator = (f2 + c1) * ((f2 + c2) * (f2 + c3)).sqrt() * (f2 + c4);
+
+    20.0 * (numerator / denominator).log10() + 2.00
+}
+
+/// C-weighting relative response at `hz`, per IEC 61672
+pub fn c_weighting_db(hz: f32) -> f32 {
+    let f2 = hz * hz;
+    let c1 = 20.6 * 20.6;
+    let c4 = 12194.0 * 12194.0;
+
+    let numerator = c4 * f2;
+    let denominator = (f2 + c1) * (f2 + c4);
+
+    20.0 * (numerator / denominator).log10() + 0.06
+}
+
+//=============================================================================
+// Time Weighting
+//=============================================================================
+
+/// Exponential time-weighting constant applied to the broadband power
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeWeighting {
+    /// 125 ms time constant, tracks transients
+    #[default]
+    Fast,
+    /// 1 s time constant, tracks the slower average level
+    Slow,
+}
+
+impl TimeWeighting {
+    /// Time constant in seconds
+    pub fn tau(&self) -> f32 {
+        match self {
+            TimeWeighting::Fast => 0.125,
+            TimeWeighting::Slow => 1.0,
+        }
+    }
+}
+
+//=============================================================================
+// Sound Level Meter
+//=============================================================================
+
+/// IEC 61672-style sound level meter built on top of the per-band envelope
+///
+/// Each band's weighting offset is precomputed once (from its center
+/// frequency) so per-block updates are just a weighted power sum plus a
+/// one-pole smoother.
+#[derive(Debug, Clone)]
+pub struct SoundLevelMeter {
+    weighting: Weighting,
+    time_weighting: TimeWeighting,
+    band_gain_linear: Vec<f32>, // power-domain gain per band
+    smoothed_power: f32,
+    mean_power: f64,
+    num_updates: u64,
+}
+
+impl SoundLevelMeter {
+    /// Create a meter for the given bands, precomputing per-band weighting gain
+    pub fn new(bands: &[BandInfo], weighting: Weighting, time_weighting: TimeWeighting) -> Self {
+        let band_gain_linear = bands
+            .iter()
+            .map(|b| 10.0_f32.powf(weighting.gain_db(b.center_hz) / 10.0))
+            .collect();
+
+        Self {
+            weighting,
+            time_weighting,
+            band_gain_linear,
+            smoothed_power: 0.0,
+            mean_power: 0.0,
+            num_updates: 0,
+        }
+    }
+
+    /// Recompute the per-band weighting gain, e.g. after the band layout changes
+    pub fn set_bands(&mut self, bands: &[BandInfo]) {
+        self.band_gain_linear = bands
+            .iter()
+            .map(|b| 10.0_f32.powf(self.weighting.gain_db(b.center_hz) / 10.0))
+            .collect();
+    }
+
+    /// Frequency weighting curve in use
+    pub fn weighting(&self) -> Weighting {
+        self.weighting
+    }
+
+    /// Time weighting constant in use
+    pub fn time_weighting(&self) -> TimeWeighting {
+        self.time_weighting
+    }
+
+    /// Fold a block's per-band magnitudes into the running level
+    ///
+    /// `dt` is the duration in seconds the block represents, used to drive
+    /// the exponential time-weighting smoother.
+    pub fn update(&mut self, magnitudes: &[f32], dt: f32) {
+        let instant_power: f32 = magnitudes
+            .iter()
+            .zip(self.band_gain_linear.iter())
+            .map(|(&mag, &gain)| mag * mag * gain)
+            .sum();
+
+        let coeff = (-dt / self.time_weighting.tau()).exp();
+        self.smoothed_power = coeff * self.smoothed_power + (1.0 - coeff) * instant_power;
+
+        self.num_updates += 1;
+        self.mean_power +=
+            (instant_power as f64 - self.mean_power) / self.num_updates as f64;
+    }
+
+    /// Current time-weighted sound level in dB
+    pub fn level_db(&self) -> f32 {
+        power_to_db(self.smoothed_power)
+    }
+
+    /// Equivalent-continuous level (Leq) over all updates so far, in dB
+    pub fn leq_db(&self) -> f32 {
+        power_to_db(self.mean_power as f32)
+    }
+
+    /// Reset the Leq accumulator and time-weighted level, keeping the configuration
+    pub fn reset(&mut self) {
+        self.smoothed_power = 0.0;
+        self.mean_power = 0.0;
+        self.num_updates = 0;
+    }
+}
+
+fn power_to_db(power: f32) -> f32 {
+    if power > 0.0 {
+        10.0 * power.log10()
+    } else {
+        f32::NEG_INFINITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_a_weighting_1khz_is_reference() {
+        // A-weighting is defined to be ~0 dB at 1 kHz
+        assert!((a_weighting_db(1000.0)).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_a_weighting_attenuates_low_frequencies() {
+        assert!(a_weighting_db(31.5) < a_weighting_db(1000.0));
+    }
+
+    #[test]
+    fn test_c_weighting_flatter_than_a_at_low_frequencies() {
+        assert!(c_weighting_db(31.5) > a_weighting_db(31.5));
+    }
+
+    #[test]
+    fn test_z_weighting_is_flat() {
+        assert_eq!(Weighting::Z.gain_db(20.0), 0.0);
+        assert_eq!(Weighting::Z.gain_db(20000.0), 0.0);
+    }
+
+    #[test]
+    fn test_leq_of_constant_level_matches_instantaneous() {
+        let bands = [BandInfo {
+            center_hz: 1000.0,
+            bandwidth_hz: 100.0,
+            low_hz: 950.0,
+            high_hz: 1050.0,
+        }];
+        let mut slm = SoundLevelMeter::new(&bands, Weighting::Z, TimeWeighting::Fast);
+
+        for _ in 0..50 {
+            slm.update(&[1.0], 0.01);
+        }
+
+        assert!((slm.leq_db() - 0.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_time_weighting_constants() {
+        assert_eq!(TimeWeighting::Fast.tau(), 0.125);
+        assert_eq!(TimeWeighting::Slow.tau(), 1.0);
+    }
+}
@@ -0,0 +1,14 @@
+//! Shared test fixtures
+//!
+//! A `sine()` generator shared by the `tests` modules of several files,
+//! so each one doesn't redefine the same "generate a test tone, process
+//! it, assert the peak lands near the source frequency" boilerplate.
+
+use std::f32::consts::PI;
+
+/// A `num_samples`-long sine wave at `freq` Hz, sampled at `sample_rate`
+pub(crate) fn sine(freq: f32, sample_rate: f32, num_samples: usize) -> Vec<f32> {
+    (0..num_samples)
+        .map(|i| (2.0 * PI * freq * i as f32 / sample_rate).sin())
+        .collect()
+}